@@ -2,7 +2,7 @@ mod client;
 mod transaction;
 mod wallet;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use clap::{Parser, Subcommand};
 use client::OuroClient;
 use colored::Colorize;
@@ -187,17 +187,11 @@ fn main() -> Result<()> {
                 Some(n) => n,
                 None => {
                     println!("{}", "Fetching nonce from blockchain...".cyan());
-                    match client.get_nonce(&wallet.address) {
-                        Ok(n) => {
-                            println!("{}", format!("Current nonce: {}", n).bright_black());
-                            n
-                        }
-                        Err(e) => {
-                            println!("{}", format!("Warning: Failed to fetch nonce: {}", e).yellow());
-                            println!("{}", "Using default nonce: 0".yellow());
-                            0
-                        }
-                    }
+                    let n = client.get_nonce(&wallet.address).map_err(|e| {
+                        anyhow!("Failed to fetch nonce: {} (pass --nonce to set it explicitly)", e)
+                    })?;
+                    println!("{}", format!("Current nonce: {}", n).bright_black());
+                    n
                 }
             };
 
@@ -112,3 +112,65 @@ impl Transaction {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+    use chrono::TimeZone;
+
+    /// Golden vectors shared with ouro_sdk and the node (see test_vectors/README.md)
+    const VECTORS: &str = include_str!("../../test_vectors/signing.json");
+
+    fn str_field(v: &serde_json::Value, key: &str) -> String {
+        v[key].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_signing_golden_vectors() {
+        let suite: serde_json::Value = serde_json::from_str(VECTORS).unwrap();
+        let suite = &suite["ed25519"];
+
+        let secret: [u8; 32] = hex::decode(suite["secret_key"].as_str().unwrap())
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let signing_key = SigningKey::from_bytes(&secret);
+        assert_eq!(hex::encode(signing_key.verifying_key().to_bytes()), str_field(suite, "public_key"));
+
+        let wallet = Wallet::from_private_key(&hex::encode(signing_key.to_keypair_bytes()), "golden".to_string()).unwrap();
+        assert_eq!(wallet.address, str_field(suite, "address"));
+
+        let vectors = suite["vectors"].as_array().unwrap();
+        let mainchain: Vec<_> = vectors.iter().filter(|v| v["scheme"] == "mainchain").collect();
+        assert!(!mainchain.is_empty());
+
+        for vector in mainchain {
+            let t = &vector["tx"];
+            let mut tx = Transaction {
+                id: str_field(t, "id"),
+                sender: str_field(t, "sender"),
+                recipient: str_field(t, "recipient"),
+                amount: t["amount"].as_u64().unwrap(),
+                timestamp: Utc.timestamp_opt(t["timestamp"].as_i64().unwrap(), 0).unwrap(),
+                parents: t["parents"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|p| p.as_str().unwrap().to_string())
+                    .collect(),
+                signature: String::new(),
+                public_key: wallet.public_key.clone(),
+                fee: t["fee"].as_u64().unwrap(),
+                payload: t["payload"].as_str().map(str::to_string),
+                chain_id: str_field(t, "chain_id"),
+                nonce: t["nonce"].as_u64().unwrap(),
+            };
+
+            let name = str_field(vector, "name");
+            assert_eq!(hex::encode(tx.signing_message()), str_field(vector, "message"), "message mismatch: {}", name);
+            tx.sign(&signing_key).unwrap();
+            assert_eq!(tx.signature, str_field(vector, "signature"), "signature mismatch: {}", name);
+        }
+    }
+}
@@ -1,9 +1,55 @@
 use crate::error::{Result, SdkError};
 use crate::transaction::Transaction;
 use crate::types::*;
-use reqwest::Client;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, Response};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde_json::json;
+use std::time::Duration;
+
+/// Retry policy for idempotent reads
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each subsequent one
+    pub initial_backoff: Duration,
+    /// Upper bound on any single delay. A server `Retry-After` hint longer than
+    /// this is not waited out; the `RateLimited` error is returned instead.
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Single-shot requests, no retrying
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Exponential backoff starting at `initial_backoff`, capped at 10s
+    pub fn exponential(max_retries: u32, initial_backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_backoff,
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+
+    /// Delay before retry number `attempt` (0-based), or `None` when the
+    /// server asked us to wait longer than `max_backoff`
+    fn backoff(&self, attempt: u32, retry_after: Option<u64>) -> Option<Duration> {
+        let exponential = self.initial_backoff.saturating_mul(2u32.saturating_pow(attempt));
+        match retry_after.map(Duration::from_secs) {
+            Some(hint) if hint > self.max_backoff => None,
+            Some(hint) => Some(exponential.max(hint).min(self.max_backoff)),
+            None => Some(exponential.min(self.max_backoff)),
+        }
+    }
+}
 
 /// Main client for interacting with Ouroboros network
 #[derive(Clone)]
@@ -12,6 +58,8 @@ pub struct OuroClient {
     pub base_url: String,
     /// HTTP client
     pub client: Client,
+    /// Retry policy applied to idempotent GET requests
+    pub retry: RetryPolicy,
 }
 
 impl OuroClient {
@@ -20,6 +68,7 @@ impl OuroClient {
         Self {
             base_url: node_url.into().trim_end_matches('/').to_string(),
             client: Client::new(),
+            retry: RetryPolicy::none(),
         }
     }
 
@@ -28,17 +77,62 @@ impl OuroClient {
         Self {
             base_url: node_url.into().trim_end_matches('/').to_string(),
             client,
+            retry: RetryPolicy::none(),
+        }
+    }
+
+    /// Retry idempotent reads (balance, nonce, status, state queries) on
+    /// transient failures. Submissions and other writes are never retried.
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// GET and decode a JSON response, retrying transient failures per `self.retry`
+    pub(crate) async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let mut attempt = 0;
+        loop {
+            let result = match self.client.get(url).send().await {
+                Ok(response) => Self::read_json(response).await,
+                Err(e) => Err(e.into()),
+            };
+
+            match result {
+                Err(e) if attempt < self.retry.max_retries && e.is_transient() => {
+                    let retry_after = match &e {
+                        SdkError::RateLimited { retry_after, .. } => *retry_after,
+                        _ => None,
+                    };
+                    match self.retry.backoff(attempt, retry_after) {
+                        Some(delay) => tokio::time::sleep(delay).await,
+                        None => return Err(e),
+                    }
+                    attempt += 1;
+                }
+                other => return other,
+            }
         }
     }
 
+    /// Decode a JSON response, mapping non-success statuses onto typed errors
+    pub(crate) async fn read_json<T: DeserializeOwned>(response: Response) -> Result<T> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response.json().await?);
+        }
+
+        let retry_after = response.headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok());
+        let body = response.text().await.unwrap_or_default();
+        Err(SdkError::from_status(status.as_u16(), retry_after, &body))
+    }
+
     /// Get mainchain balance for address
     pub async fn get_balance(&self, address: &str) -> Result<Balance> {
         let url = format!("{}/balance/{}", self.base_url, address);
-        let response: BalanceResponse = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: BalanceResponse = self.get_json(&url).await?;
 
         Ok(Balance {
             address: address.to_string(),
@@ -47,14 +141,18 @@ impl OuroClient {
         })
     }
 
+    /// Get the next expected nonce for address (0 for never-seen addresses)
+    pub async fn get_nonce(&self, address: &str) -> Result<u64> {
+        let url = format!("{}/ouro/nonce/{}", self.base_url, address);
+        let response: NonceResponse = self.get_json(&url).await?;
+
+        Ok(response.nonce)
+    }
+
     /// Get microchain balance
     pub async fn get_microchain_balance(&self, microchain_id: &str, address: &str) -> Result<u64> {
         let url = format!("{}/microchain/{}/balance/{}", self.base_url, microchain_id, address);
-        let response: MicrochainBalanceResponse = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: MicrochainBalanceResponse = self.get_json(&url).await?;
 
         Ok(response.balance)
     }
@@ -62,12 +160,11 @@ impl OuroClient {
     /// Submit transaction to mainchain
     pub async fn submit_transaction(&self, tx: &Transaction) -> Result<String> {
         let url = format!("{}/tx/submit", self.base_url);
-        let response: TxSubmitResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .json(tx)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: TxSubmitResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.tx_id)
@@ -81,11 +178,7 @@ impl OuroClient {
     /// Get transaction status
     pub async fn get_transaction_status(&self, tx_id: &str) -> Result<TxStatus> {
         let url = format!("{}/tx/{}", self.base_url, tx_id);
-        let response: TxStatusResponse = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: TxStatusResponse = self.get_json(&url).await?;
 
         Ok(match response.status.as_str() {
             "pending" => TxStatus::Pending,
@@ -99,12 +192,11 @@ impl OuroClient {
     /// Create a new microchain
     pub async fn create_microchain(&self, config: &MicrochainConfig) -> Result<String> {
         let url = format!("{}/microchain/create", self.base_url);
-        let response: CreateMicrochainResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .json(config)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: CreateMicrochainResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.microchain_id)
@@ -118,11 +210,7 @@ impl OuroClient {
     /// Get microchain state
     pub async fn get_microchain_state(&self, microchain_id: &str) -> Result<MicrochainState> {
         let url = format!("{}/microchain/{}/state", self.base_url, microchain_id);
-        let state: MicrochainState = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let state: MicrochainState = self.get_json(&url).await?;
 
         Ok(state)
     }
@@ -130,11 +218,7 @@ impl OuroClient {
     /// List all microchains
     pub async fn list_microchains(&self) -> Result<Vec<MicrochainState>> {
         let url = format!("{}/microchains", self.base_url);
-        let response: ListMicrochainsResponse = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: ListMicrochainsResponse = self.get_json(&url).await?;
 
         Ok(response.microchains)
     }
@@ -142,11 +226,10 @@ impl OuroClient {
     /// Trigger manual anchor for a microchain
     pub async fn anchor_microchain(&self, microchain_id: &str) -> Result<String> {
         let url = format!("{}/microchain/{}/anchor", self.base_url, microchain_id);
-        let response: AnchorResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: AnchorResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.anchor_id)
@@ -169,11 +252,7 @@ impl OuroClient {
     /// Get subchain status
     pub async fn get_subchain_status(&self, subchain_id: &str) -> Result<crate::subchain::SubchainStatus> {
         let url = format!("{}/subchain/{}/status", self.base_url, subchain_id);
-        let response: SubchainStatusResponse = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: SubchainStatusResponse = self.get_json(&url).await?;
 
         Ok(crate::subchain::SubchainStatus {
             id: response.id,
@@ -197,7 +276,7 @@ impl OuroClient {
     /// Register a new subchain
     pub async fn register_subchain(&self, config: &crate::subchain::SubchainConfig) -> Result<String> {
         let url = format!("{}/subchain/register", self.base_url);
-        let response: RegisterSubchainResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .json(&json!({
                 "name": config.name,
                 "owner": config.owner,
@@ -207,9 +286,8 @@ impl OuroClient {
                 "validators": config.validators,
             }))
             .send()
-            .await?
-            .json()
             .await?;
+        let response: RegisterSubchainResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.subchain_id)
@@ -223,12 +301,11 @@ impl OuroClient {
     /// Top up subchain rent
     pub async fn top_up_subchain_rent(&self, subchain_id: &str, amount: u64) -> Result<String> {
         let url = format!("{}/subchain/{}/topup", self.base_url, subchain_id);
-        let response: GenericTxResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .json(&json!({ "amount": amount }))
             .send()
-            .await?
-            .json()
             .await?;
+        let response: GenericTxResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.tx_id.unwrap_or_default())
@@ -242,11 +319,7 @@ impl OuroClient {
     /// Get subchain balance
     pub async fn get_subchain_balance(&self, subchain_id: &str, address: &str) -> Result<u64> {
         let url = format!("{}/subchain/{}/balance/{}", self.base_url, subchain_id, address);
-        let response: MicrochainBalanceResponse = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: MicrochainBalanceResponse = self.get_json(&url).await?;
 
         Ok(response.balance)
     }
@@ -254,11 +327,10 @@ impl OuroClient {
     /// Anchor subchain to mainchain
     pub async fn anchor_subchain(&self, subchain_id: &str) -> Result<String> {
         let url = format!("{}/subchain/{}/anchor", self.base_url, subchain_id);
-        let response: AnchorResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: AnchorResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.anchor_id)
@@ -272,12 +344,11 @@ impl OuroClient {
     /// Add validator to subchain
     pub async fn add_subchain_validator(&self, subchain_id: &str, validator: &crate::subchain::ValidatorConfig) -> Result<String> {
         let url = format!("{}/subchain/{}/validators", self.base_url, subchain_id);
-        let response: GenericTxResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .json(validator)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: GenericTxResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.tx_id.unwrap_or_default())
@@ -291,11 +362,10 @@ impl OuroClient {
     /// Remove validator from subchain
     pub async fn remove_subchain_validator(&self, subchain_id: &str, pubkey: &str) -> Result<String> {
         let url = format!("{}/subchain/{}/validators/{}", self.base_url, subchain_id, pubkey);
-        let response: GenericTxResponse = self.client.delete(&url)
+        let response = self.client.delete(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: GenericTxResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.tx_id.unwrap_or_default())
@@ -309,11 +379,7 @@ impl OuroClient {
     /// Get subchain validators
     pub async fn get_subchain_validators(&self, subchain_id: &str) -> Result<Vec<crate::subchain::ValidatorConfig>> {
         let url = format!("{}/subchain/{}/validators", self.base_url, subchain_id);
-        let response: ValidatorsResponse = self.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: ValidatorsResponse = self.get_json(&url).await?;
 
         Ok(response.validators)
     }
@@ -321,11 +387,10 @@ impl OuroClient {
     /// Withdraw subchain deposit
     pub async fn withdraw_subchain_deposit(&self, subchain_id: &str) -> Result<String> {
         let url = format!("{}/subchain/{}/withdraw", self.base_url, subchain_id);
-        let response: GenericTxResponse = self.client.post(&url)
+        let response = self.client.post(&url)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: GenericTxResponse = Self::read_json(response).await?;
 
         if response.success {
             Ok(response.tx_id.unwrap_or_default())
@@ -344,6 +409,11 @@ struct BalanceResponse {
     pending: Option<u64>,
 }
 
+#[derive(Deserialize)]
+struct NonceResponse {
+    nonce: u64,
+}
+
 #[derive(Deserialize)]
 struct MicrochainBalanceResponse {
     balance: u64,
@@ -416,6 +486,7 @@ struct ValidatorsResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
 
     #[test]
     fn test_client_creation() {
@@ -428,4 +499,128 @@ mod tests {
         let client = OuroClient::new("http://localhost:8001/");
         assert_eq!(client.base_url, "http://localhost:8001");
     }
+
+    fn mock_response(status: u16, headers: &[(&str, &str)], body: &str) -> Response {
+        let mut builder = http::Response::builder().status(status);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        Response::from(builder.body(body.to_string()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_read_json_maps_statuses() {
+        let envelope = r#"{"error": {"code": "unauthorized", "message": "Invalid API key"}}"#;
+        let err = OuroClient::read_json::<serde_json::Value>(mock_response(401, &[], envelope))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SdkError::Unauthorized(m) if m == "Invalid API key"));
+
+        let err = OuroClient::read_json::<serde_json::Value>(mock_response(404, &[], ""))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SdkError::NotFound(_)));
+
+        let err = OuroClient::read_json::<serde_json::Value>(mock_response(429, &[("Retry-After", "12")], ""))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SdkError::RateLimited { retry_after: Some(12), .. }));
+
+        let err = OuroClient::read_json::<serde_json::Value>(mock_response(502, &[], "bad gateway"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SdkError::Server { status: 502, message } if message == "bad gateway"));
+
+        let ok = OuroClient::read_json::<NonceResponse>(mock_response(200, &[], r#"{"nonce": 3}"#))
+            .await
+            .unwrap();
+        assert_eq!(ok.nonce, 3);
+    }
+
+    const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+    /// Serve canned HTTP responses in order, one per connection.
+    /// Each request line received (e.g. `GET /ouro/nonce/x HTTP/1.1`) is sent on the returned channel.
+    async fn serve_sequence(responses: Vec<&'static str>) -> (String, UnboundedReceiver<String>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (requests_tx, requests_rx) = unbounded_channel();
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let request = String::from_utf8_lossy(&buf[..n]);
+                let _ = requests_tx.send(request.lines().next().unwrap_or_default().to_string());
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+        (format!("http://{}", addr), requests_rx)
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_transient_failures() {
+        const OK: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 12\r\nConnection: close\r\n\r\n{\"nonce\": 5}";
+
+        let (url, _) = serve_sequence(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let client = OuroClient::new(url)
+            .with_retry(RetryPolicy::exponential(3, Duration::from_millis(10)));
+        assert_eq!(client.get_nonce("ouro1abc").await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_without_policy() {
+        let (url, _) = serve_sequence(vec![UNAVAILABLE]).await;
+        let client = OuroClient::new(url);
+        let err = client.get_nonce("ouro1abc").await.unwrap_err();
+        assert!(matches!(err, SdkError::Server { status: 503, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_long_retry_after_is_not_retried() {
+        const LIMITED: &str = "HTTP/1.1 429 Too Many Requests\r\nRetry-After: 60\r\nContent-Length: 0\r\nConnection: close\r\n\r\n";
+
+        let (url, _) = serve_sequence(vec![LIMITED]).await;
+        let client = OuroClient::new(url)
+            .with_retry(RetryPolicy::exponential(3, Duration::from_millis(10)));
+        let err = client.get_nonce("ouro1abc").await.unwrap_err();
+        assert!(matches!(err, SdkError::RateLimited { retry_after: Some(60), .. }));
+    }
+
+    #[tokio::test]
+    async fn test_submit_is_never_retried() {
+        const OK: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 32\r\nConnection: close\r\n\r\n{\"success\": true, \"tx_id\": \"t1\"}";
+
+        let (url, _) = serve_sequence(vec![UNAVAILABLE, OK]).await;
+        let client = OuroClient::new(url)
+            .with_retry(RetryPolicy::exponential(3, Duration::from_millis(10)));
+        let tx = Transaction::new("ouro1alice", "ouro1bob", 100);
+        let err = client.submit_transaction(&tx).await.unwrap_err();
+        assert!(matches!(err, SdkError::Server { status: 503, .. }));
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy::exponential(5, Duration::from_millis(100));
+        assert_eq!(policy.backoff(0, None), Some(Duration::from_millis(100)));
+        assert_eq!(policy.backoff(2, None), Some(Duration::from_millis(400)));
+        assert_eq!(policy.backoff(0, Some(2)), Some(Duration::from_secs(2)));
+        assert_eq!(policy.backoff(20, None), Some(policy.max_backoff));
+        assert_eq!(policy.backoff(0, Some(10)), Some(policy.max_backoff));
+        // A hint beyond the cap gives up rather than retrying early
+        assert_eq!(policy.backoff(0, Some(60)), None);
+    }
+
+    #[tokio::test]
+    async fn test_get_nonce_request_path() {
+        const OK: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 12\r\nConnection: close\r\n\r\n{\"nonce\": 7}";
+
+        let (url, mut requests) = serve_sequence(vec![OK]).await;
+        let client = OuroClient::new(url);
+        assert_eq!(client.get_nonce("ouro1abc").await.unwrap(), 7);
+        assert_eq!(requests.recv().await.unwrap(), "GET /ouro/nonce/ouro1abc HTTP/1.1");
+    }
 }
@@ -1,3 +1,4 @@
+use serde::Deserialize;
 use thiserror::Error;
 
 pub type Result<T> = std::result::Result<T, SdkError>;
@@ -31,6 +32,18 @@ pub enum SdkError {
     #[error("RPC error: {0}")]
     Rpc(String),
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Rate limited: {message}")]
+    RateLimited { message: String, retry_after: Option<u64> },
+
+    #[error("Server error {status}: {message}")]
+    Server { status: u16, message: String },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -49,3 +62,87 @@ impl From<&str> for SdkError {
         SdkError::Other(s.to_string())
     }
 }
+
+/// Node error body: `{"error": {"code", "message", "details"?}}`, or the flat
+/// `{"error": "<message>"}` sent by the Python Medium/Light nodes
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+    error: ErrorBody,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ErrorBody {
+    Message(String),
+    Structured { message: String },
+}
+
+impl ErrorBody {
+    fn into_message(self) -> String {
+        match self {
+            ErrorBody::Message(message) | ErrorBody::Structured { message } => message,
+        }
+    }
+}
+
+impl SdkError {
+    /// Map a non-success HTTP response onto a typed error.
+    ///
+    /// Uses the message from the error envelope (nested or flat) when present,
+    /// otherwise the raw body text.
+    pub fn from_status(status: u16, retry_after: Option<u64>, body: &str) -> Self {
+        let message = match serde_json::from_str::<ErrorEnvelope>(body) {
+            Ok(envelope) => envelope.error.into_message(),
+            Err(_) if body.trim().is_empty() => format!("HTTP {}", status),
+            Err(_) => body.trim().to_string(),
+        };
+
+        match status {
+            401 | 403 => SdkError::Unauthorized(message),
+            404 => SdkError::NotFound(message),
+            429 => SdkError::RateLimited { message, retry_after },
+            500..=599 => SdkError::Server { status, message },
+            _ => SdkError::Rpc(format!("HTTP {}: {}", status, message)),
+        }
+    }
+
+    /// Whether retrying the same request could plausibly succeed
+    pub fn is_transient(&self) -> bool {
+        match self {
+            SdkError::Network(e) => e.is_connect() || e.is_timeout(),
+            SdkError::RateLimited { .. } => true,
+            SdkError::Server { status, .. } => matches!(status, 502..=504),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_mapping() {
+        assert!(matches!(SdkError::from_status(401, None, ""), SdkError::Unauthorized(_)));
+        assert!(matches!(SdkError::from_status(404, None, "no such tx"), SdkError::NotFound(m) if m == "no such tx"));
+        assert!(matches!(
+            SdkError::from_status(429, Some(30), ""),
+            SdkError::RateLimited { retry_after: Some(30), .. }
+        ));
+        assert!(matches!(SdkError::from_status(503, None, ""), SdkError::Server { status: 503, .. }));
+        assert!(matches!(SdkError::from_status(400, None, "bad"), SdkError::Rpc(_)));
+        assert!(matches!(
+            SdkError::from_status(403, None, r#"{"error": "Invalid API key"}"#),
+            SdkError::Unauthorized(m) if m == "Invalid API key"
+        ));
+    }
+
+    #[test]
+    fn test_error_envelope_message() {
+        let body = r#"{"error": {"code": "not_found", "message": "Transaction not found", "details": null}}"#;
+        match SdkError::from_status(404, None, body) {
+            SdkError::NotFound(message) => assert_eq!(message, "Transaction not found"),
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+}
@@ -8,7 +8,7 @@ pub mod error;
 pub use microchain::{Microchain, MicrochainBuilder};
 pub use subchain::{Subchain, SubchainBuilder, SubchainConfig, SubchainStatus, ValidatorConfig};
 pub use transaction::{Transaction, TransactionBuilder};
-pub use client::OuroClient;
+pub use client::{OuroClient, RetryPolicy};
 pub use types::{MicrochainConfig, ConsensusType, AnchorFrequency};
 pub use error::{SdkError, Result};
 
@@ -20,7 +20,7 @@ pub mod prelude {
     pub use crate::microchain::Microchain;
     pub use crate::subchain::{Subchain, SubchainBuilder, SubchainConfig};
     pub use crate::transaction::Transaction;
-    pub use crate::client::OuroClient;
+    pub use crate::client::{OuroClient, RetryPolicy};
     pub use crate::types::*;
     pub use crate::error::{SdkError, Result};
 }
@@ -56,12 +56,11 @@ impl Microchain {
     /// Submit a transaction to this microchain
     pub async fn submit_tx(&mut self, tx: &Transaction) -> Result<String> {
         let url = format!("{}/microchain/{}/tx", self.client.base_url, self.id);
-        let response: serde_json::Value = self.client.client.post(&url)
+        let response = self.client.client.post(&url)
             .json(tx)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: serde_json::Value = OuroClient::read_json(response).await?;
 
         if response["success"].as_bool().unwrap_or(false) {
             self.nonce += 1;
@@ -94,11 +93,7 @@ impl Microchain {
         let url = format!("{}/microchain/{}/txs?from={}&to={}",
             self.client.base_url, self.id, from, to);
 
-        let response: TxHistoryResponse = self.client.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: TxHistoryResponse = self.client.get_json(&url).await?;
 
         Ok(response.transactions)
     }
@@ -108,11 +103,7 @@ impl Microchain {
         let url = format!("{}/microchain/{}/blocks?limit={}",
             self.client.base_url, self.id, limit);
 
-        let response: BlocksResponse = self.client.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: BlocksResponse = self.client.get_json(&url).await?;
 
         Ok(response.blocks)
     }
@@ -193,12 +193,11 @@ impl Subchain {
     /// Submit a transaction to this subchain
     pub async fn submit_tx(&mut self, tx: &Transaction) -> Result<String> {
         let url = format!("{}/subchain/{}/tx", self.client.base_url, self.id);
-        let response: serde_json::Value = self.client.client.post(&url)
+        let response = self.client.client.post(&url)
             .json(tx)
             .send()
-            .await?
-            .json()
             .await?;
+        let response: serde_json::Value = OuroClient::read_json(response).await?;
 
         if response["success"].as_bool().unwrap_or(false) {
             self.nonce += 1;
@@ -231,11 +230,7 @@ impl Subchain {
         let url = format!("{}/subchain/{}/txs?from={}&to={}",
             self.client.base_url, self.id, from, to);
 
-        let response: TxHistoryResponse = self.client.client.get(&url)
-            .send()
-            .await?
-            .json()
-            .await?;
+        let response: TxHistoryResponse = self.client.get_json(&url).await?;
 
         Ok(response.transactions)
     }
@@ -198,4 +198,38 @@ mod tests {
 
         assert!(result.is_err()); // Missing 'to' address
     }
+
+    /// Golden vectors shared with midgard_wallet and the node (see test_vectors/README.md)
+    const VECTORS: &str = include_str!("../../test_vectors/signing.json");
+
+    #[test]
+    fn test_signing_golden_vectors() {
+        let suite: serde_json::Value = serde_json::from_str(VECTORS).unwrap();
+        let suite = &suite["ed25519"];
+        let secret_key = suite["secret_key"].as_str().unwrap();
+
+        let vectors = suite["vectors"].as_array().unwrap();
+        let microchain: Vec<_> = vectors.iter().filter(|v| v["scheme"] == "microchain").collect();
+        assert!(!microchain.is_empty());
+
+        for vector in microchain {
+            let t = &vector["tx"];
+            let mut tx = Transaction::new(
+                t["from"].as_str().unwrap(),
+                t["to"].as_str().unwrap(),
+                t["amount"].as_u64().unwrap(),
+            )
+            .with_nonce(t["nonce"].as_u64().unwrap());
+            tx.id = t["id"].as_str().unwrap().to_string();
+
+            let name = vector["name"].as_str().unwrap();
+            assert_eq!(
+                hex::encode(tx.signing_message()),
+                vector["message"].as_str().unwrap(),
+                "message mismatch: {}", name
+            );
+            tx.sign_with_key(secret_key).unwrap();
+            assert_eq!(tx.signature, vector["signature"].as_str().unwrap(), "signature mismatch: {}", name);
+        }
+    }
 }